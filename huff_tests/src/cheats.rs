@@ -0,0 +1,44 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// All cheatcodes recognized by [`CheatsInspector`](crate::inspectors::cheats_inspector::CheatsInspector).
+///
+/// Each variant corresponds to the `cheat_key` a Huffmate cheat macro pushes onto the stack
+/// before calling [`CHEATS_ADDR`](crate::inspectors::cheats_inspector::CHEATS_ADDR).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HuffCheatCode {
+    /// Logs a value to stdout, mirroring Huffmate's `LOG` macro.
+    Log,
+    /// Overwrites `block.timestamp` for the remainder of the test.
+    Warp,
+    /// Overwrites `block.number` for the remainder of the test.
+    Roll,
+    /// Sets the balance of an account.
+    Deal,
+    /// Writes a storage slot of a target account.
+    Store,
+    /// Reads a storage slot of a target account.
+    Load,
+    /// Overrides the caller for the next call frame.
+    Prank,
+    /// Arms the test so the next call is expected to revert, optionally matching a specific
+    /// revert data prefix.
+    ExpectRevert,
+}
+
+lazy_static! {
+    /// Maps the `cheat_key` pushed onto the stack by a Huffmate cheat macro to its
+    /// corresponding [`HuffCheatCode`] variant.
+    pub static ref HUFF_CHEATS_MAP: HashMap<u32, HuffCheatCode> = {
+        let mut m = HashMap::new();
+        m.insert(0x01, HuffCheatCode::Log);
+        m.insert(0x02, HuffCheatCode::Warp);
+        m.insert(0x03, HuffCheatCode::Roll);
+        m.insert(0x04, HuffCheatCode::Deal);
+        m.insert(0x05, HuffCheatCode::Store);
+        m.insert(0x06, HuffCheatCode::Load);
+        m.insert(0x07, HuffCheatCode::Prank);
+        m.insert(0x08, HuffCheatCode::ExpectRevert);
+        m
+    };
+}
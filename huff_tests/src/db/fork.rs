@@ -0,0 +1,175 @@
+use alloy_primitives::{Address, Bytes, B256, U256};
+use revm::{
+    db::{AccountState, CacheDB, EmptyDB},
+    primitives::{AccountInfo, Bytecode},
+    Database,
+};
+use std::fmt;
+
+/// Error returned while fetching account state from a forked JSON-RPC endpoint.
+#[derive(Debug, Clone)]
+pub struct ForkError(pub String);
+
+impl fmt::Display for ForkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fork error: {}", self.0)
+    }
+}
+
+impl std::error::Error for ForkError {}
+
+/// A [`Database`] that lazily fetches account info, code, and storage from a JSON-RPC endpoint
+/// pinned to a specific block, caching every value it fetches so repeat reads never touch the
+/// network.
+#[derive(Debug)]
+pub struct ForkDb {
+    rpc_url: String,
+    block: u64,
+    cache: CacheDB<EmptyDB>,
+}
+
+impl ForkDb {
+    /// Create a new forking database that reads state as of `block` from `rpc_url`.
+    pub fn new(rpc_url: String, block: u64) -> Self {
+        Self { rpc_url, block, cache: CacheDB::new(EmptyDB::default()) }
+    }
+
+    /// Give [`super::TestDatabase`] a way to write directly into the local cache, e.g. to apply
+    /// `deal`/`store` cheatcodes without a round trip through the RPC.
+    pub(super) fn cache_mut(&mut self) -> &mut CacheDB<EmptyDB> {
+        &mut self.cache
+    }
+
+    fn block_tag(&self) -> String {
+        format!("0x{:x}", self.block)
+    }
+
+    fn rpc_call(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, ForkError> {
+        let body =
+            serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+
+        let response: serde_json::Value = ureq::post(&self.rpc_url)
+            .send_json(body)
+            .map_err(|e| ForkError(format!("{method}: {e}")))?
+            .into_json()
+            .map_err(|e| ForkError(format!("{method}: {e}")))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(ForkError(format!("{method}: {error}")));
+        }
+
+        Ok(response["result"].clone())
+    }
+
+    fn fetch_account(&self, address: Address) -> Result<AccountInfo, ForkError> {
+        let tag = self.block_tag();
+
+        let balance: U256 = serde_json::from_value(
+            self.rpc_call("eth_getBalance", serde_json::json!([address, tag]))?,
+        )
+        .map_err(|e| ForkError(e.to_string()))?;
+        let nonce: U256 = serde_json::from_value(
+            self.rpc_call("eth_getTransactionCount", serde_json::json!([address, tag]))?,
+        )
+        .map_err(|e| ForkError(e.to_string()))?;
+        let code: Bytes = serde_json::from_value(
+            self.rpc_call("eth_getCode", serde_json::json!([address, tag]))?,
+        )
+        .map_err(|e| ForkError(e.to_string()))?;
+
+        let nonce = nonce
+            .try_into()
+            .map_err(|_| ForkError(format!("account {address} nonce {nonce} overflows u64")))?;
+
+        let bytecode = Bytecode::new_raw(code);
+        Ok(AccountInfo { balance, nonce, code_hash: bytecode.hash_slow(), code: Some(bytecode) })
+    }
+
+    fn fetch_storage(&self, address: Address, index: U256) -> Result<U256, ForkError> {
+        let tag = self.block_tag();
+        serde_json::from_value(
+            self.rpc_call("eth_getStorageAt", serde_json::json!([address, index, tag]))?,
+        )
+        .map_err(|e| ForkError(e.to_string()))
+    }
+
+    /// Fetch the pinned block's header fields needed to populate
+    /// [`Env::block`](revm::primitives::BlockEnv).
+    pub(crate) fn fetch_block(&self) -> Result<ForkBlock, ForkError> {
+        let tag = self.block_tag();
+        let block = self.rpc_call("eth_getBlockByNumber", serde_json::json!([tag, false]))?;
+
+        if block.is_null() {
+            return Err(ForkError(format!("block {} not found", self.block)));
+        }
+
+        let field = |name: &str| -> Result<U256, ForkError> {
+            serde_json::from_value(block[name].clone())
+                .map_err(|e| ForkError(format!("block.{name}: {e}")))
+        };
+
+        Ok(ForkBlock {
+            number: field("number")?,
+            timestamp: field("timestamp")?,
+            basefee: field("baseFeePerGas").unwrap_or(U256::ZERO),
+        })
+    }
+}
+
+/// The subset of a block header needed to populate [`Env::block`](revm::primitives::BlockEnv)
+/// when running against a fork.
+pub(crate) struct ForkBlock {
+    pub number: U256,
+    pub timestamp: U256,
+    pub basefee: U256,
+}
+
+impl Database for ForkDb {
+    type Error = ForkError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        if let Some(info) = self.cache.accounts.get(&address).and_then(|a| a.info()) {
+            return Ok(Some(info));
+        }
+
+        let info = self.fetch_account(address)?;
+        self.cache.insert_account_info(address, info.clone());
+        // An entry created earlier by a storage write starts out as `NotExisting`, which would
+        // hide the fetched info from the cache; mark it as a known account.
+        if let Some(account) = self.cache.accounts.get_mut(&address) {
+            account.account_state = AccountState::None;
+        }
+        Ok(Some(info))
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        Database::code_by_hash(&mut self.cache, code_hash).map_err(|_| {
+            ForkError(format!("code for hash {code_hash} was not fetched alongside an account"))
+        })
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        let cached =
+            self.cache.accounts.get(&address).is_some_and(|a| a.storage.contains_key(&index));
+        if cached {
+            return Database::storage(&mut self.cache, address, index)
+                .map_err(|_| ForkError("cached storage slot went missing".to_owned()));
+        }
+
+        let value = self.fetch_storage(address, index)?;
+        self.cache
+            .insert_account_storage(address, index, value)
+            .map_err(|e| ForkError(format!("{e:?}")))?;
+        Ok(value)
+    }
+
+    fn block_hash(&mut self, number: U256) -> Result<B256, Self::Error> {
+        Database::block_hash(&mut self.cache, number).map_err(|_| {
+            ForkError(format!("block hash for block {number} is not available while forked"))
+        })
+    }
+}
@@ -0,0 +1,92 @@
+mod fork;
+
+use crate::types::RunnerError;
+pub use fork::ForkDb;
+
+use alloy_primitives::{Address, B256, U256};
+use revm::{
+    primitives::{Account, AccountInfo, Bytecode},
+    Database, DatabaseCommit, InMemoryDB,
+};
+use std::collections::HashMap;
+
+/// The database backing a [`TestRunner`](crate::runner::TestRunner): either the default
+/// in-memory chain, or one that lazily pulls account state from a forked JSON-RPC endpoint.
+#[derive(Debug)]
+pub enum TestDatabase {
+    InMemory(InMemoryDB),
+    Fork(ForkDb),
+}
+
+impl Default for TestDatabase {
+    fn default() -> Self {
+        TestDatabase::InMemory(InMemoryDB::default())
+    }
+}
+
+impl TestDatabase {
+    /// Overwrite an account's info directly in the local cache, bypassing the RPC when forked.
+    pub fn insert_account_info(&mut self, address: Address, info: AccountInfo) {
+        match self {
+            TestDatabase::InMemory(db) => db.insert_account_info(address, info),
+            TestDatabase::Fork(db) => db.cache_mut().insert_account_info(address, info),
+        }
+    }
+}
+
+impl Database for TestDatabase {
+    type Error = RunnerError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        match self {
+            TestDatabase::InMemory(db) => {
+                db.basic(address).map_err(|e| RunnerError::Database(format!("{e:?}")))
+            }
+            TestDatabase::Fork(db) => {
+                db.basic(address).map_err(|e| RunnerError::Database(e.to_string()))
+            }
+        }
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        match self {
+            TestDatabase::InMemory(db) => {
+                db.code_by_hash(code_hash).map_err(|e| RunnerError::Database(format!("{e:?}")))
+            }
+            TestDatabase::Fork(db) => {
+                db.code_by_hash(code_hash).map_err(|e| RunnerError::Database(e.to_string()))
+            }
+        }
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        match self {
+            TestDatabase::InMemory(db) => {
+                db.storage(address, index).map_err(|e| RunnerError::Database(format!("{e:?}")))
+            }
+            TestDatabase::Fork(db) => {
+                db.storage(address, index).map_err(|e| RunnerError::Database(e.to_string()))
+            }
+        }
+    }
+
+    fn block_hash(&mut self, number: U256) -> Result<B256, Self::Error> {
+        match self {
+            TestDatabase::InMemory(db) => {
+                db.block_hash(number).map_err(|e| RunnerError::Database(format!("{e:?}")))
+            }
+            TestDatabase::Fork(db) => {
+                db.block_hash(number).map_err(|e| RunnerError::Database(e.to_string()))
+            }
+        }
+    }
+}
+
+impl DatabaseCommit for TestDatabase {
+    fn commit(&mut self, changes: HashMap<Address, Account>) {
+        match self {
+            TestDatabase::InMemory(db) => db.commit(changes),
+            TestDatabase::Fork(db) => db.cache_mut().commit(changes),
+        }
+    }
+}
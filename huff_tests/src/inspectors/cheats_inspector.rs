@@ -1,5 +1,5 @@
 use crate::cheats::{HuffCheatCode, HUFF_CHEATS_MAP};
-use alloy_primitives::{hex, Address, Log};
+use alloy_primitives::{hex, Address, Log, U256};
 use lazy_static::lazy_static;
 use revm::{
     interpreter::{
@@ -18,6 +18,101 @@ lazy_static! {
 #[derive(Debug, Default)]
 pub struct CheatsInspector {
     pub logs: Vec<(u32, String)>,
+    /// The caller to apply to the next call frame, set by the `prank` cheatcode.
+    next_caller_override: Option<Address>,
+    /// Set by the `expectRevert` cheatcode. `Some(prefix)` arms the test to expect the next call
+    /// to revert, matching `prefix` against the start of the revert data (an empty prefix matches
+    /// any revert).
+    pub expect_revert: Option<Vec<u8>>,
+}
+
+/// Read the 32-byte word at `offset` within `input`.
+fn word(input: &[u8], offset: usize) -> &[u8] {
+    &input[offset..offset + 32]
+}
+
+/// Interpret a 32-byte word as a right-aligned `Address`.
+fn word_to_address(word: &[u8]) -> Address {
+    Address::from_slice(&word[12..32])
+}
+
+impl CheatsInspector {
+    /// Overwrite `context.env.block.timestamp` with the value encoded in `inputs.input[64..96]`.
+    fn warp<DB: Database>(&mut self, context: &mut EvmContext<DB>, inputs: &CallInputs) {
+        context.env.block.timestamp = U256::from_be_slice(word(&inputs.input, 64));
+    }
+
+    /// Overwrite `context.env.block.number` with the value encoded in `inputs.input[64..96]`.
+    fn roll<DB: Database>(&mut self, context: &mut EvmContext<DB>, inputs: &CallInputs) {
+        context.env.block.number = U256::from_be_slice(word(&inputs.input, 64));
+    }
+
+    /// Set the balance of the account at `inputs.input[64..96]` to the amount encoded in
+    /// `inputs.input[96..128]`, mirroring
+    /// [`TestRunner::set_balance`](crate::runner::TestRunner::set_balance).
+    ///
+    /// Returns `false` if the backing database failed to load the account, e.g. a dead or
+    /// corrupt forked RPC endpoint.
+    fn deal<DB: Database>(&mut self, context: &mut EvmContext<DB>, inputs: &CallInputs) -> bool {
+        let address = word_to_address(word(&inputs.input, 64));
+        let amount = U256::from_be_slice(word(&inputs.input, 96));
+
+        match context.journaled_state.load_account(address, &mut context.db) {
+            Ok((account, _)) => {
+                account.info.balance = amount;
+                account.mark_touch();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Write `inputs.input[128..160]` to the storage slot `inputs.input[96..128]` of the account
+    /// at `inputs.input[64..96]`, through `context.journaled_state` so the change is visible to
+    /// the running bytecode.
+    ///
+    /// Returns `false` if the backing database failed to read or write the slot.
+    fn store<DB: Database>(&mut self, context: &mut EvmContext<DB>, inputs: &CallInputs) -> bool {
+        let address = word_to_address(word(&inputs.input, 64));
+        let slot = U256::from_be_slice(word(&inputs.input, 96));
+        let value = U256::from_be_slice(word(&inputs.input, 128));
+
+        context.journaled_state.load_account(address, &mut context.db).is_ok()
+            && context.journaled_state.sload(address, slot, &mut context.db).is_ok()
+            && context.journaled_state.sstore(address, slot, value, &mut context.db).is_ok()
+    }
+
+    /// Read the storage slot `inputs.input[96..128]` of the account at `inputs.input[64..96]`
+    /// through `context.journaled_state`.
+    ///
+    /// Returns `Err` if the backing database failed to read the slot.
+    fn load<DB: Database>(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &CallInputs,
+    ) -> Result<U256, ()> {
+        let address = word_to_address(word(&inputs.input, 64));
+        let slot = U256::from_be_slice(word(&inputs.input, 96));
+
+        context.journaled_state.load_account(address, &mut context.db).map_err(|_| ())?;
+        context
+            .journaled_state
+            .sload(address, slot, &mut context.db)
+            .map(|(value, _)| value)
+            .map_err(|_| ())
+    }
+
+    /// Override the caller for the next call frame with the address encoded in
+    /// `inputs.input[64..96]`.
+    fn prank(&mut self, inputs: &CallInputs) {
+        self.next_caller_override = Some(word_to_address(word(&inputs.input, 64)));
+    }
+
+    /// Arm the test to expect its next call to revert, optionally matching the revert data
+    /// against the prefix encoded in `inputs.input[64..]`.
+    fn expect_revert(&mut self, inputs: &CallInputs) {
+        self.expect_revert = Some(inputs.input[64..].to_vec());
+    }
 }
 
 impl<DB> Inspector<DB> for CheatsInspector
@@ -31,14 +126,18 @@ where
     fn call(
         &mut self,
         _context: &mut EvmContext<DB>,
-        _inputs: &mut CallInputs,
+        inputs: &mut CallInputs,
     ) -> Option<CallOutcome> {
+        if let Some(caller) = self.next_caller_override.take() {
+            inputs.caller = caller;
+        }
+
         None
     }
 
     fn call_end(
         &mut self,
-        _context: &mut EvmContext<DB>,
+        context: &mut EvmContext<DB>,
         inputs: &CallInputs,
         outcome: CallOutcome,
     ) -> CallOutcome {
@@ -51,45 +150,93 @@ where
             let cheat_key = bytes_to_u32(&inputs.input[0..32]);
             let pc = bytes_to_u32(&inputs.input[32..64]);
 
-            if let Some(HuffCheatCode::Log) = HUFF_CHEATS_MAP.get(&cheat_key) {
-                // In Huffmate, the LOG macro sends 96 bytes of calldata to our cheatcode
-                // address, laid out as follows:
-                // ╔════════╦═══════════════╗
-                // ║ Offset ║     Value     ║
-                // ╠════════╬═══════════════╣
-                // ║ 0x00   ║ cheat_key     ║
-                // ║ 0x20   ║ pc            ║
-                // ║ 0x40   ║ log_item      ║
-                // ╚════════╩═══════════════╝
-                //
-                // #define macro LOG() = takes (1) {
-                //     // Input stack:   [log_item]
-                //     pc             // [pc, log_item]
-                //     0x01           // [log_cheatcode, pc, log_item]
-                //     0x00 mstore    // [pc, log_item]
-                //     0x20 mstore    // [log_item]
-                //     0x40 mstore    // []
-                //     0x00 dup1      // [0x00, 0x00]
-                //     0x60 dup2      // [0x00, 0x60, 0x00, 0x00]
-                //     0x00000000000000000000000000000000bEefbabe
-                //     gas            // [gas, beef_babe, 0x00, 0x60, 0x00, 0x00]
-                //     staticcall pop // []
-                // }
-
-                // Check if we have exactly one 32 byte input
-                if inputs.input.len() != 96 {
-                    return CallOutcome::new(
-                        InterpreterResult::new(
-                            InstructionResult::Revert,
-                            outcome.output().clone(),
-                            outcome.gas(),
-                        ),
-                        outcome.memory_offset,
-                    );
+            // Each cheatcode expects a fixed-width calldata layout of `cheat_key`/`pc` followed
+            // by its arguments, one 32-byte word apiece. `expectRevert` is the one exception, as
+            // its optional match prefix is variable-length.
+            let expected_len = match HUFF_CHEATS_MAP.get(&cheat_key) {
+                Some(HuffCheatCode::Log) => Some(96),
+                Some(HuffCheatCode::Warp) => Some(96),
+                Some(HuffCheatCode::Roll) => Some(96),
+                Some(HuffCheatCode::Prank) => Some(96),
+                Some(HuffCheatCode::Load) => Some(128),
+                Some(HuffCheatCode::Deal) => Some(128),
+                Some(HuffCheatCode::Store) => Some(160),
+                Some(HuffCheatCode::ExpectRevert) | None => None,
+            };
+
+            let revert = |outcome: &CallOutcome| {
+                CallOutcome::new(
+                    InterpreterResult::new(
+                        InstructionResult::Revert,
+                        outcome.output().clone(),
+                        outcome.gas(),
+                    ),
+                    outcome.memory_offset,
+                )
+            };
+
+            if let Some(expected_len) = expected_len {
+                if inputs.input.len() != expected_len {
+                    return revert(&outcome);
                 }
+            }
 
-                let log_item = hex::encode(&inputs.input[64..96]);
-                self.logs.push((pc, log_item));
+            match HUFF_CHEATS_MAP.get(&cheat_key) {
+                Some(HuffCheatCode::Log) => {
+                    // In Huffmate, the LOG macro sends 96 bytes of calldata to our cheatcode
+                    // address, laid out as follows:
+                    // ╔════════╦═══════════════╗
+                    // ║ Offset ║     Value     ║
+                    // ╠════════╬═══════════════╣
+                    // ║ 0x00   ║ cheat_key     ║
+                    // ║ 0x20   ║ pc            ║
+                    // ║ 0x40   ║ log_item      ║
+                    // ╚════════╩═══════════════╝
+                    //
+                    // #define macro LOG() = takes (1) {
+                    //     // Input stack:   [log_item]
+                    //     pc             // [pc, log_item]
+                    //     0x01           // [log_cheatcode, pc, log_item]
+                    //     0x00 mstore    // [pc, log_item]
+                    //     0x20 mstore    // [log_item]
+                    //     0x40 mstore    // []
+                    //     0x00 dup1      // [0x00, 0x00]
+                    //     0x60 dup2      // [0x00, 0x60, 0x00, 0x00]
+                    //     0x00000000000000000000000000000000bEefbabe
+                    //     gas            // [gas, beef_babe, 0x00, 0x60, 0x00, 0x00]
+                    //     staticcall pop // []
+                    // }
+                    let log_item = hex::encode(word(&inputs.input, 64));
+                    self.logs.push((pc, log_item));
+                }
+                Some(HuffCheatCode::Warp) => self.warp(context, inputs),
+                Some(HuffCheatCode::Roll) => self.roll(context, inputs),
+                Some(HuffCheatCode::Deal) => {
+                    if !self.deal(context, inputs) {
+                        return revert(&outcome);
+                    }
+                }
+                Some(HuffCheatCode::Store) => {
+                    if !self.store(context, inputs) {
+                        return revert(&outcome);
+                    }
+                }
+                Some(HuffCheatCode::Load) => match self.load(context, inputs) {
+                    Ok(value) => {
+                        return CallOutcome::new(
+                            InterpreterResult::new(
+                                InstructionResult::Return,
+                                value.to_be_bytes_vec().into(),
+                                outcome.gas(),
+                            ),
+                            outcome.memory_offset,
+                        )
+                    }
+                    Err(()) => return revert(&outcome),
+                },
+                Some(HuffCheatCode::Prank) => self.prank(inputs),
+                Some(HuffCheatCode::ExpectRevert) => self.expect_revert(inputs),
+                None => {}
             }
         }
 
@@ -0,0 +1,87 @@
+use crate::inspectors::{cheats_inspector::CheatsInspector, tracing_inspector::TracingInspector};
+use alloy_primitives::Log;
+use revm::{
+    interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter},
+    Database, EvmContext, Inspector,
+};
+
+/// Combines [`CheatsInspector`] and an optional [`TracingInspector`] so a single test call can
+/// honor cheatcodes while also recording a gas trace, without either inspector knowing about
+/// the other.
+#[derive(Debug, Default)]
+pub struct CompositeInspector {
+    pub cheats: CheatsInspector,
+    pub tracer: Option<TracingInspector>,
+}
+
+impl<DB> Inspector<DB> for CompositeInspector
+where
+    DB: Database,
+{
+    fn log(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>, log: &Log) {
+        self.cheats.log(interp, context, log);
+    }
+
+    fn step(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.step(interp, context);
+        }
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.step_end(interp, context);
+        }
+    }
+
+    fn call(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.call(context, inputs);
+        }
+
+        self.cheats.call(context, inputs)
+    }
+
+    fn call_end(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &CallInputs,
+        outcome: CallOutcome,
+    ) -> CallOutcome {
+        let outcome = self.cheats.call_end(context, inputs, outcome);
+
+        match &mut self.tracer {
+            Some(tracer) => tracer.call_end(context, inputs, outcome),
+            None => outcome,
+        }
+    }
+
+    fn create(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &mut CreateInputs,
+    ) -> Option<CreateOutcome> {
+        match &mut self.tracer {
+            Some(tracer) => tracer.create(context, inputs),
+            None => None,
+        }
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &CreateInputs,
+        outcome: CreateOutcome,
+    ) -> CreateOutcome {
+        let outcome = self.cheats.create_end(context, inputs, outcome);
+
+        match &mut self.tracer {
+            Some(tracer) => tracer.create_end(context, inputs, outcome),
+            None => outcome,
+        }
+    }
+}
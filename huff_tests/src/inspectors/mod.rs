@@ -0,0 +1,3 @@
+pub mod cheats_inspector;
+pub mod composite_inspector;
+pub mod tracing_inspector;
@@ -0,0 +1,111 @@
+use crate::trace::{CallTrace, OpcodeTrace};
+use revm::{
+    interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter},
+    Database, EvmContext, Inspector,
+};
+
+/// An [`Inspector`] that records a call trace tree and the gas cost of every opcode executed,
+/// so test authors can find which macro regions dominate gas usage.
+#[derive(Debug, Default)]
+pub struct TracingInspector {
+    /// Frames currently being built, outermost first. The last entry is the active frame.
+    stack: Vec<CallTrace>,
+    /// The completed root frame, populated once the outermost call returns.
+    root: Option<CallTrace>,
+    /// Gas remaining as of the last `step`, used to derive the cost of each opcode in `step_end`.
+    gas_remaining_at_step: u64,
+    /// The pc and opcode about to execute as of the last `step`, since by `step_end` the
+    /// interpreter has already moved on to the next instruction.
+    pc_at_step: usize,
+    opcode_at_step: u8,
+}
+
+impl TracingInspector {
+    /// Consume the inspector and return the root call trace, if any call was made.
+    pub fn into_trace(mut self) -> Option<CallTrace> {
+        self.root.take()
+    }
+
+    fn finish_frame(&mut self, mut frame: CallTrace, output: alloy_primitives::Bytes) {
+        frame.output = output;
+        match self.stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root = Some(frame),
+        }
+    }
+}
+
+impl<DB> Inspector<DB> for TracingInspector
+where
+    DB: Database,
+{
+    fn step(&mut self, interp: &mut Interpreter, _context: &mut EvmContext<DB>) {
+        self.gas_remaining_at_step = interp.gas.remaining();
+        self.pc_at_step = interp.program_counter();
+        self.opcode_at_step = interp.current_opcode();
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        let Some(frame) = self.stack.last_mut() else { return };
+
+        frame.opcodes.push(OpcodeTrace {
+            pc: self.pc_at_step,
+            opcode: self.opcode_at_step,
+            gas_cost: self.gas_remaining_at_step.saturating_sub(interp.gas.remaining()),
+            depth: context.journaled_state.depth() as u64,
+        });
+    }
+
+    fn call(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        self.stack.push(CallTrace {
+            address: inputs.target_address,
+            input: inputs.input.clone(),
+            ..Default::default()
+        });
+
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CallInputs,
+        outcome: CallOutcome,
+    ) -> CallOutcome {
+        if let Some(frame) = self.stack.pop() {
+            self.finish_frame(frame, outcome.output().clone());
+        }
+
+        outcome
+    }
+
+    fn create(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        inputs: &mut CreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.stack.push(CallTrace { input: inputs.init_code.clone(), ..Default::default() });
+
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CreateInputs,
+        outcome: CreateOutcome,
+    ) -> CreateOutcome {
+        if let Some(mut frame) = self.stack.pop() {
+            if let Some(address) = outcome.address() {
+                frame.address = *address;
+            }
+            self.finish_frame(frame, outcome.output().clone());
+        }
+
+        outcome
+    }
+}
@@ -0,0 +1,9 @@
+//! Execution and testing utilities for compiled Huff bytecode.
+
+pub mod cheats;
+pub mod db;
+pub mod inspectors;
+pub mod prelude;
+pub mod runner;
+pub mod trace;
+pub mod types;
@@ -0,0 +1,8 @@
+pub use crate::{
+    cheats::{HuffCheatCode, HUFF_CHEATS_MAP},
+    db::{ForkDb, TestDatabase},
+    inspectors::{cheats_inspector, composite_inspector, tracing_inspector},
+    runner::TestRunner,
+    trace::{CallTrace, OpcodeTrace},
+    types::{RunnerError, TestResult, TestStatus},
+};
@@ -1,4 +1,8 @@
-use crate::prelude::{cheats_inspector::CheatsInspector, RunnerError, TestResult, TestStatus};
+use crate::prelude::{
+    cheats_inspector::CheatsInspector, composite_inspector::CompositeInspector,
+    tracing_inspector::TracingInspector, ForkDb, RunnerError, TestDatabase, TestResult,
+    TestStatus,
+};
 use alloy_primitives::{hex, Address, Bytes, U256};
 use huff_codegen::Codegen;
 use huff_utils::{
@@ -6,37 +10,52 @@ use huff_utils::{
     prelude::{pad_n_bytes, CompilerError, Contract, EVMVersion},
 };
 use revm::{
-    db::DbAccount,
     primitives::{Env, ExecutionResult, Output, TransactTo, LATEST},
-    Database, Evm, InMemoryDB,
+    Database, Evm,
 };
 
-/// The test runner allows execution of test macros within an in-memory REVM
-/// instance.
+/// The test runner allows execution of test macros within an in-memory or forked REVM instance.
 #[derive(Default, Debug)]
 pub struct TestRunner {
-    pub database: InMemoryDB,
+    pub database: TestDatabase,
     pub env: Env,
 }
 
 impl TestRunner {
+    /// Create a runner backed by a forked JSON-RPC endpoint, pinned to `block`.
+    ///
+    /// Fetches the pinned block's header so `env.block` (number/timestamp/basefee) reflects the
+    /// fork point, matching the account and storage reads which are pinned to the same block.
+    pub fn fork(rpc_url: String, block: u64) -> Result<Self, RunnerError> {
+        let db = ForkDb::new(rpc_url, block);
+        let header = db.fetch_block().map_err(|e| RunnerError::Database(e.to_string()))?;
+
+        let mut env = Env::default();
+        env.block.number = header.number;
+        env.block.timestamp = header.timestamp;
+        env.block.basefee = header.basefee;
+
+        Ok(Self { database: TestDatabase::Fork(db), env })
+    }
+
     /// Get a mutable reference to the database.
-    pub fn db_mut(&mut self) -> &mut InMemoryDB {
+    pub fn db_mut(&mut self) -> &mut TestDatabase {
         &mut self.database
     }
 
     /// Set the balance of an account.
-    pub fn set_balance(&mut self, address: Address, amount: U256) -> &mut Self {
+    pub fn set_balance(
+        &mut self,
+        address: Address,
+        amount: U256,
+    ) -> Result<&mut Self, RunnerError> {
         let db = self.db_mut();
 
-        let mut account = match db.basic(address) {
-            Ok(Some(info)) => DbAccount { info, ..Default::default() },
-            _ => DbAccount::new_not_existing(),
-        };
-        account.info.balance = amount.into();
-        db.insert_account_info(address, account.info);
+        let mut info = db.basic(address)?.unwrap_or_default();
+        info.balance = amount;
+        db.insert_account_info(address, info);
 
-        self
+        Ok(self)
     }
 
     /// Deploy arbitrary bytecode to our REVM instance and return the contract address.
@@ -76,7 +95,7 @@ impl TestRunner {
             hex::decode(bootstrap).expect("Invalid hex").into(),
             U256::ZERO,
         );
-        self.set_balance(Address::ZERO, U256::MAX);
+        self.set_balance(Address::ZERO, U256::MAX)?;
         let mut evm = Evm::builder()
             .with_spec_id(LATEST)
             .with_env(Box::new(env))
@@ -91,24 +110,28 @@ impl TestRunner {
             ExecutionResult::Success { output: Output::Create(_, Some(addr)), .. } => addr,
 
             ExecutionResult::Revert { gas_used, output } => {
-                return Err(RunnerError(format!(
-                    "Deployment reverted gas_used={}, output={:?}",
-                    gas_used, output
-                )));
+                return Err(RunnerError::DeploymentReverted { gas_used, output });
             }
             ExecutionResult::Halt { reason, gas_used } => {
-                return Err(RunnerError(format!(
-                    "Deployment halted gas_used={}, reason={:?}",
-                    gas_used, reason
-                )));
+                return Err(RunnerError::DeploymentHalted {
+                    reason: format!("{reason:?} (gas_used={gas_used})"),
+                });
+            }
+            _ => {
+                return Err(RunnerError::UnexpectedExecutionResult(String::from(
+                    "deployment did not produce a contract address",
+                )))
             }
-            _ => return Err(RunnerError(String::from("Unexpected transaction status"))),
         };
 
         Ok(address)
     }
 
-    /// Perform a call to a deployed contract
+    /// Perform a call to a deployed contract.
+    ///
+    /// `expect_revert` mirrors the runtime `expectRevert` cheatcode: when `true`, the call is
+    /// expected to revert and [`TestResult::status`] is flipped accordingly, matching any revert
+    /// data.
     pub fn call(
         &mut self,
         name: String,
@@ -116,17 +139,21 @@ impl TestRunner {
         address: Address,
         value: U256,
         data: String,
+        trace: bool,
+        expect_revert: bool,
     ) -> Result<TestResult, RunnerError> {
-        let env = self.build_env(
-            caller,
-            TransactTo::Call(address),
-            hex::decode(data).expect("Invalid calldata").into(),
-            value,
-        );
+        let calldata = hex::decode(data).map_err(|_| RunnerError::InvalidCalldata)?;
+        let env = self.build_env(caller, TransactTo::Call(address), calldata.into(), value);
 
-        let inspector = CheatsInspector::default();
+        let inspector = CompositeInspector {
+            cheats: CheatsInspector {
+                expect_revert: expect_revert.then(Vec::new),
+                ..Default::default()
+            },
+            tracer: trace.then(TracingInspector::default),
+        };
 
-        self.set_balance(caller, U256::MAX);
+        self.set_balance(caller, U256::MAX)?;
         let mut evm = Evm::builder()
             .with_spec_id(LATEST)
             .with_env(Box::new(env))
@@ -141,35 +168,45 @@ impl TestRunner {
         let gas_used = match er {
             ExecutionResult::Success { gas_used, .. } => gas_used,
             ExecutionResult::Revert { gas_used, .. } => gas_used,
-            _ => return Err(RunnerError(String::from("Unexpected transaction status"))),
-        };
-        let status = match er {
-            ExecutionResult::Success { .. } => TestStatus::Success,
-            _ => TestStatus::Revert,
+            _ => {
+                return Err(RunnerError::UnexpectedExecutionResult(String::from(
+                    "call did not succeed or revert",
+                )))
+            }
         };
+        let reverted = matches!(er, ExecutionResult::Revert { .. });
 
         // Check if the transaction was successful
-        let return_data = match er {
-            ExecutionResult::Success { output, .. } => {
-                if let Output::Call(b) = output {
-                    if b.is_empty() {
-                        None
-                    } else {
-                        Some(hex::encode(b))
-                    }
-                } else {
-                    return Err(RunnerError(String::from("Unexpected transaction kind")));
-                }
-            }
-            ExecutionResult::Revert { output, .. } => {
-                if output.is_empty() {
-                    None
-                } else {
-                    Some(hex::encode(output))
+        let output = match er {
+            ExecutionResult::Success { output, .. } => match output {
+                Output::Call(output) => output,
+                Output::Create(..) => {
+                    return Err(RunnerError::UnexpectedExecutionResult(String::from(
+                        "call produced a create output",
+                    )))
                 }
+            },
+            ExecutionResult::Revert { output, .. } => output,
+            _ => {
+                return Err(RunnerError::UnexpectedExecutionResult(String::from(
+                    "call did not succeed or revert",
+                )))
             }
-            _ => return Err(RunnerError(String::from("Unexpected transaction status"))),
         };
+        let return_data = if output.is_empty() { None } else { Some(hex::encode(&output)) };
+
+        // A test may arm `expectRevert` (via the `expect_revert` param or the runtime cheatcode)
+        // to declare that its call is supposed to revert, optionally matching a specific revert
+        // data prefix. This flips the pass/fail interpretation of the call below.
+        let armed_revert_prefix = evm.context.external.cheats.expect_revert.take();
+        let status = match armed_revert_prefix {
+            Some(prefix) if reverted && output.starts_with(&prefix) => TestStatus::Success,
+            Some(_) => TestStatus::Revert,
+            None if reverted => TestStatus::Revert,
+            None => TestStatus::Success,
+        };
+
+        let trace = evm.context.external.tracer.take().and_then(TracingInspector::into_trace);
 
         // Return our test result
         // NOTE: We subtract 21000 gas from the gas result to account for the
@@ -179,7 +216,8 @@ impl TestRunner {
             return_data,
             gas: gas_used - 21000,
             status,
-            logs: evm.context.external.logs,
+            logs: evm.context.external.cheats.logs,
+            trace,
         })
     }
 
@@ -188,6 +226,7 @@ impl TestRunner {
         &mut self,
         m: &MacroDefinition,
         contract: &Contract,
+        trace: bool,
     ) -> Result<TestResult, RunnerError> {
         // TODO: set to non default
         let evm_version = EVMVersion::default();
@@ -214,6 +253,7 @@ impl TestRunner {
                     // Set environment flags passed through the test decorator
                     let mut data = String::default();
                     let mut value = U256::ZERO;
+                    let mut expect_revert = false;
                     if let Some(decorator) = &m.decorator {
                         for flag in &decorator.flags {
                             match flag {
@@ -226,12 +266,17 @@ impl TestRunner {
                                     };
                                 }
                                 DecoratorFlag::Value(v) => value = U256::from_be_bytes(*v),
+                                // `#[revert]` statically declares that this test's call is
+                                // expected to revert, the decorator-level equivalent of arming
+                                // the runtime `expectRevert` cheatcode.
+                                DecoratorFlag::Revert => expect_revert = true,
                             }
                         }
                     }
 
                     // Call the deployed test
-                    let res = self.call(name, Address::ZERO, address, value, data)?;
+                    let res =
+                        self.call(name, Address::ZERO, address, value, data, trace, expect_revert)?;
                     Ok(res)
                 }
                 Err(e) => Err(CompilerError::CodegenError(e).into()),
@@ -241,10 +286,12 @@ impl TestRunner {
     }
 
     /// Build an EVM transaction environment.
+    ///
+    /// Starts from `self.env` so a forked runner's pinned `block.{number,timestamp,basefee}`
+    /// carries through to every deployment and call.
     fn build_env(&self, caller: Address, to: TransactTo, data: Bytes, value: U256) -> Env {
-        let mut env = Env::default();
+        let mut env = self.env.clone();
         env.cfg.chain_id = 1;
-        env.block.basefee = U256::ZERO;
         env.block.gas_limit = U256::MAX;
         env.tx.chain_id = 1.into();
         env.tx.caller = caller;
@@ -0,0 +1,22 @@
+use alloy_primitives::{Address, Bytes};
+
+/// A single opcode executed while a [`TracingInspector`](crate::inspectors::tracing_inspector::TracingInspector)
+/// is attached, along with its gas cost and call depth.
+#[derive(Debug, Clone)]
+pub struct OpcodeTrace {
+    pub pc: usize,
+    pub opcode: u8,
+    pub gas_cost: u64,
+    pub depth: u64,
+}
+
+/// A CALL or CREATE frame captured by [`TracingInspector`](crate::inspectors::tracing_inspector::TracingInspector),
+/// along with the opcodes executed directly within it and any nested frames.
+#[derive(Debug, Clone, Default)]
+pub struct CallTrace {
+    pub address: Address,
+    pub input: Bytes,
+    pub output: Bytes,
+    pub opcodes: Vec<OpcodeTrace>,
+    pub calls: Vec<CallTrace>,
+}
@@ -0,0 +1,105 @@
+use crate::trace::CallTrace;
+use alloy_primitives::{hex, Bytes, U256};
+use huff_utils::prelude::CompilerError;
+use revm::primitives::EVMError;
+use std::fmt;
+
+/// Decode a standard Solidity `Error(string)` or `Panic(uint256)` revert payload into a
+/// human-readable message, if `data` matches one of those layouts.
+pub fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    if data.len() < 4 {
+        return None;
+    }
+    let (selector, payload) = data.split_at(4);
+    match selector {
+        // Error(string)
+        [0x08, 0xc3, 0x79, 0xa0] => {
+            let len = usize::try_from(U256::from_be_slice(payload.get(32..64)?)).ok()?;
+            let end = 64usize.checked_add(len)?;
+            std::str::from_utf8(payload.get(64..end)?).ok().map(ToOwned::to_owned)
+        }
+        // Panic(uint256)
+        [0x4e, 0x48, 0x7b, 0x71] => {
+            Some(format!("panic: {:#x}", U256::from_be_slice(payload.get(0..32)?)))
+        }
+        _ => None,
+    }
+}
+
+/// Error type returned by [`TestRunner`](crate::runner::TestRunner) operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunnerError {
+    /// The deployment transaction reverted.
+    DeploymentReverted { gas_used: u64, output: Bytes },
+    /// The deployment transaction halted, e.g. by running out of gas.
+    DeploymentHalted { reason: String },
+    /// The calldata passed to a call could not be decoded as hex.
+    InvalidCalldata,
+    /// The underlying EVM returned something other than the `Call`/`Create` result expected.
+    UnexpectedExecutionResult(String),
+    /// An error raised by the underlying EVM itself, e.g. a transaction validation error.
+    Evm(String),
+    /// An error raised while compiling a test macro to bytecode.
+    Compiler(String),
+    /// An error raised by the backing [`TestDatabase`](crate::db::TestDatabase), e.g. a forked
+    /// RPC lookup that failed or returned corrupt data.
+    Database(String),
+}
+
+impl fmt::Display for RunnerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunnerError::DeploymentReverted { gas_used, output } => {
+                let reason =
+                    decode_revert_reason(output).unwrap_or_else(|| hex::encode_prefixed(output));
+                write!(f, "deployment reverted (gas_used={gas_used}): {reason}")
+            }
+            RunnerError::DeploymentHalted { reason } => write!(f, "deployment halted: {reason}"),
+            RunnerError::InvalidCalldata => write!(f, "invalid calldata"),
+            RunnerError::UnexpectedExecutionResult(msg) => {
+                write!(f, "unexpected execution result: {msg}")
+            }
+            RunnerError::Evm(msg) => write!(f, "evm error: {msg}"),
+            RunnerError::Compiler(msg) => write!(f, "compiler error: {msg}"),
+            RunnerError::Database(msg) => write!(f, "database error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for RunnerError {}
+
+impl From<EVMError<RunnerError>> for RunnerError {
+    fn from(e: EVMError<RunnerError>) -> Self {
+        match e {
+            // Errors from the backing `TestDatabase` are already `RunnerError`s.
+            EVMError::Database(e) => e,
+            e => RunnerError::Evm(format!("{e:?}")),
+        }
+    }
+}
+
+impl From<CompilerError> for RunnerError {
+    fn from(e: CompilerError) -> Self {
+        RunnerError::Compiler(e.to_string())
+    }
+}
+
+/// The outcome of running a single test macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestStatus {
+    Success,
+    Revert,
+}
+
+/// The result of compiling and executing a test macro within a [`TestRunner`](crate::runner::TestRunner).
+#[derive(Debug, Clone)]
+pub struct TestResult {
+    pub name: String,
+    pub return_data: Option<String>,
+    pub gas: u64,
+    pub status: TestStatus,
+    pub logs: Vec<(u32, String)>,
+    /// The call trace and per-opcode gas breakdown, present when the test was run with tracing
+    /// enabled.
+    pub trace: Option<CallTrace>,
+}
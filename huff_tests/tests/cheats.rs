@@ -0,0 +1,20 @@
+use huff_tests::cheats::{HuffCheatCode, HUFF_CHEATS_MAP};
+
+#[test]
+fn maps_every_cheat_key_to_its_variant() {
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x01), Some(&HuffCheatCode::Log));
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x02), Some(&HuffCheatCode::Warp));
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x03), Some(&HuffCheatCode::Roll));
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x04), Some(&HuffCheatCode::Deal));
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x05), Some(&HuffCheatCode::Store));
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x06), Some(&HuffCheatCode::Load));
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x07), Some(&HuffCheatCode::Prank));
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x08), Some(&HuffCheatCode::ExpectRevert));
+}
+
+#[test]
+fn has_no_entries_beyond_the_known_cheat_keys() {
+    assert_eq!(HUFF_CHEATS_MAP.len(), 8);
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x00), None);
+    assert_eq!(HUFF_CHEATS_MAP.get(&0x09), None);
+}
@@ -0,0 +1,29 @@
+use alloy_primitives::{Address, U256};
+use huff_tests::prelude::{ForkDb, TestDatabase};
+use revm::Database;
+
+#[test]
+fn defaults_to_an_empty_in_memory_database() {
+    let mut db = TestDatabase::default();
+    assert!(matches!(db, TestDatabase::InMemory(_)));
+    assert_eq!(db.basic(Address::ZERO).unwrap(), None);
+}
+
+#[test]
+fn insert_account_info_is_visible_through_basic() {
+    let mut db = TestDatabase::default();
+    let address = Address::repeat_byte(0x11);
+
+    let mut info = revm::primitives::AccountInfo::default();
+    info.balance = U256::from(42);
+    db.insert_account_info(address, info);
+
+    let fetched = db.basic(address).unwrap().expect("account was inserted");
+    assert_eq!(fetched.balance, U256::from(42));
+}
+
+#[test]
+fn fork_variant_wraps_a_fork_db() {
+    let db = TestDatabase::Fork(ForkDb::new("http://localhost:8545".to_owned(), 123));
+    assert!(matches!(db, TestDatabase::Fork(_)));
+}
@@ -0,0 +1,103 @@
+use alloy_primitives::{Address, U256};
+use huff_tests::prelude::*;
+
+/// Runtime bytecode for a contract that just `STOP`s.
+const STOP: &str = "00";
+
+/// Runtime bytecode for a contract that immediately `REVERT`s with no data.
+const REVERT: &str = "60006000fd";
+
+/// Runtime bytecode for a contract that arms `expectRevert` (with an empty, always-matching
+/// prefix) via a staticcall to the Huffmate cheats address, then `REVERT`s with no data.
+///
+/// Lays out the cheat calldata at memory `[0x00..0x40)` as `(cheat_key=0x08, pc=0x00)`,
+/// mirroring the layout documented in `CheatsInspector::call_end`.
+fn expect_revert_armed_then_revert() -> String {
+    [
+        "7f0000000000000000000000000000000000000000000000000000000000000008", // PUSH32 cheat_key
+        "6000", // PUSH1 0x00
+        "52",   // MSTORE
+        "7f0000000000000000000000000000000000000000000000000000000000000000", // PUSH32 pc
+        "6020", // PUSH1 0x20
+        "52",   // MSTORE
+        "6000", // PUSH1 0 (retSize)
+        "6000", // PUSH1 0 (retOffset)
+        "6040", // PUSH1 64 (argsSize)
+        "6000", // PUSH1 0 (argsOffset)
+        "7300000000000000000000000000000000bEefbabe", // PUSH20 CHEATS_ADDR
+        "5a",   // GAS
+        "fa",   // STATICCALL
+        "50",   // POP
+        "6000", // PUSH1 0
+        "6000", // PUSH1 0
+        "fd",   // REVERT
+    ]
+    .concat()
+}
+
+fn call(runner: &mut TestRunner, name: &str, address: Address, trace: bool) -> TestResult {
+    runner
+        .call(name.to_owned(), Address::ZERO, address, U256::ZERO, String::new(), trace, false)
+        .expect("call")
+}
+
+#[test]
+fn successful_call_reports_success() {
+    let mut runner = TestRunner::default();
+    let address = runner.deploy_code(STOP.to_owned()).expect("deploy");
+
+    let result = call(&mut runner, "stops", address, false);
+
+    assert_eq!(result.status, TestStatus::Success);
+    assert!(result.trace.is_none());
+}
+
+#[test]
+fn reverting_call_reports_revert_by_default() {
+    let mut runner = TestRunner::default();
+    let address = runner.deploy_code(REVERT.to_owned()).expect("deploy");
+
+    let result = call(&mut runner, "reverts", address, false);
+
+    assert_eq!(result.status, TestStatus::Revert);
+}
+
+#[test]
+fn expect_revert_cheatcode_flips_a_revert_into_success() {
+    let mut runner = TestRunner::default();
+    let address = runner.deploy_code(expect_revert_armed_then_revert()).expect("deploy");
+
+    let result = call(&mut runner, "expects_revert", address, false);
+
+    assert_eq!(result.status, TestStatus::Success);
+}
+
+#[test]
+fn expect_revert_param_flips_a_revert_into_success() {
+    let mut runner = TestRunner::default();
+    let address = runner.deploy_code(REVERT.to_owned()).expect("deploy");
+
+    let result = runner
+        .call(
+            "expects_revert_decorator".to_owned(),
+            Address::ZERO,
+            address,
+            U256::ZERO,
+            String::new(),
+            false,
+            true,
+        )
+        .expect("call");
+
+    assert_eq!(result.status, TestStatus::Success);
+}
+
+#[test]
+fn tracing_populates_a_call_trace() {
+    let mut runner = TestRunner::default();
+    let address = runner.deploy_code(STOP.to_owned()).expect("deploy");
+
+    let result = call(&mut runner, "traced", address, true);
+
+    assert!(result.trace.is_some());
+}
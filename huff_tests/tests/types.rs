@@ -0,0 +1,46 @@
+use huff_tests::types::decode_revert_reason;
+
+#[test]
+fn decodes_error_string() {
+    // Error(string) selector, followed by the standard ABI-encoded string "bad".
+    let data = hex_bytes(
+        "08c379a0\
+         0000000000000000000000000000000000000000000000000000000000000020\
+         0000000000000000000000000000000000000000000000000000000000000003\
+         6261640000000000000000000000000000000000000000000000000000000000",
+    );
+    assert_eq!(decode_revert_reason(&data), Some("bad".to_owned()));
+}
+
+#[test]
+fn decodes_panic_uint256() {
+    // Panic(uint256) selector, code 0x01 (assertion failed).
+    let data = hex_bytes(
+        "4e487b71\
+         0000000000000000000000000000000000000000000000000000000000000001",
+    );
+    assert_eq!(decode_revert_reason(&data), Some("panic: 0x1".to_owned()));
+}
+
+#[test]
+fn returns_none_for_unrecognized_selector_or_short_data() {
+    assert_eq!(decode_revert_reason(&hex_bytes("deadbeef")), None);
+    assert_eq!(decode_revert_reason(&hex_bytes("de")), None);
+    assert_eq!(decode_revert_reason(&[]), None);
+}
+
+#[test]
+fn does_not_panic_on_oversized_string_length() {
+    // Error(string) selector with a `len` word equal to u64::MAX (usize::MAX on 64-bit targets),
+    // which must fall through to `None` instead of overflowing the `64 + len` bounds computation.
+    let data = hex_bytes(
+        "08c379a0\
+         0000000000000000000000000000000000000000000000000000000000000020\
+         000000000000000000000000000000000000000000000000ffffffffffffffff",
+    );
+    assert_eq!(decode_revert_reason(&data), None);
+}
+
+fn hex_bytes(s: &str) -> Vec<u8> {
+    alloy_primitives::hex::decode(s).unwrap()
+}